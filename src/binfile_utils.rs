@@ -2,6 +2,9 @@ use std::collections::HashMap;
 use std::convert::TryInto;
 use std::error::Error;
 use std::fmt;
+use std::fs::File;
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
 pub struct InvalidFileType {
@@ -52,61 +55,386 @@ impl fmt::Display for RangeError {
 
 impl Error for RangeError {}
 
+#[derive(Debug, Clone)]
+pub struct UnexpectedEof {
+    needed: usize,
+    remaining: usize,
+}
+
+impl fmt::Display for UnexpectedEof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unexpected end of file. Needed {} byte(s), but only {} remaining", self.needed, self.remaining)
+    }
+}
+
+impl Error for UnexpectedEof {}
+
+/// Codec used to store a section's bytes on disk.
+///
+/// `None` stores the bytes verbatim; `Zstd` (behind the `compress-zstd` cargo
+/// feature) stores a zstd-compressed blob. A compressed section's on-disk bytes
+/// are a small header `{ magic: b"zCMP", codec: u8, uncompressed_len: u64 (le) }`
+/// followed by the codec's output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    None,
+    Zstd,
+}
+
+impl Codec {
+    fn to_u8(self) -> u8 {
+        match self {
+            Codec::None => 0,
+            Codec::Zstd => 1,
+        }
+    }
+
+    fn from_u8(value: u8) -> Result<Codec, Box<dyn Error>> {
+        match value {
+            0 => Ok(Codec::None),
+            1 => Ok(Codec::Zstd),
+            other => Err(Box::new(RangeError::new(&format!("Unknown codec: {}", other)))),
+        }
+    }
+
+    fn compress(self, data: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+        match self {
+            Codec::None => Ok(data.to_vec()),
+            Codec::Zstd => {
+                #[cfg(feature = "compress-zstd")]
+                {
+                    Ok(zstd::stream::encode_all(data, 0)?)
+                }
+                #[cfg(not(feature = "compress-zstd"))]
+                {
+                    Err(Box::new(RangeError::new("zstd support not enabled; build with feature `compress-zstd`")))
+                }
+            }
+        }
+    }
+
+    fn decompress(self, data: &[u8], uncompressed_len: usize) -> Result<Vec<u8>, Box<dyn Error>> {
+        match self {
+            Codec::None => Ok(data.to_vec()),
+            Codec::Zstd => {
+                #[cfg(feature = "compress-zstd")]
+                {
+                    let out = zstd::stream::decode_all(data)?;
+                    if out.len() != uncompressed_len {
+                        return Err(Box::new(RangeError::new("Decompressed length mismatch")));
+                    }
+                    Ok(out)
+                }
+                #[cfg(not(feature = "compress-zstd"))]
+                {
+                    let _ = uncompressed_len;
+                    Err(Box::new(RangeError::new("zstd support not enabled; build with feature `compress-zstd`")))
+                }
+            }
+        }
+    }
+}
+
+/// Checksum algorithm used to protect a section's on-disk bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgo {
+    Crc32,
+    Sha256,
+}
+
+impl ChecksumAlgo {
+    fn to_u8(self) -> u8 {
+        match self {
+            ChecksumAlgo::Crc32 => 0,
+            ChecksumAlgo::Sha256 => 1,
+        }
+    }
+
+    fn from_u8(value: u8) -> Result<ChecksumAlgo, Box<dyn Error>> {
+        match value {
+            0 => Ok(ChecksumAlgo::Crc32),
+            1 => Ok(ChecksumAlgo::Sha256),
+            other => Err(Box::new(RangeError::new(&format!("Unknown checksum algorithm: {}", other)))),
+        }
+    }
+
+    fn digest(self, data: &[u8]) -> Checksum {
+        match self {
+            ChecksumAlgo::Crc32 => {
+                let mut hasher = crc32fast::Hasher::new();
+                hasher.update(data);
+                Checksum::Crc32(hasher.finalize())
+            }
+            ChecksumAlgo::Sha256 => {
+                use sha2::{Digest, Sha256};
+                let mut hasher = Sha256::new();
+                hasher.update(data);
+                Checksum::Sha256(hasher.finalize().into())
+            }
+        }
+    }
+}
+
+/// A computed or expected section digest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Checksum {
+    Crc32(u32),
+    Sha256([u8; 32]),
+}
+
+impl Checksum {
+    fn algo(&self) -> ChecksumAlgo {
+        match self {
+            Checksum::Crc32(_) => ChecksumAlgo::Crc32,
+            Checksum::Sha256(_) => ChecksumAlgo::Sha256,
+        }
+    }
+
+    fn write_to(&self, out: &mut Vec<u8>) {
+        match self {
+            Checksum::Crc32(value) => out.extend_from_slice(&value.to_le_bytes()),
+            Checksum::Sha256(digest) => out.extend_from_slice(digest),
+        }
+    }
+}
+
+/// Magic prefixing a compressed-section container, so the parser can flag such
+/// a section in the in-memory section table and decompress it transparently.
+const COMPRESSED_MAGIC: [u8; 4] = *b"zCMP";
+
+/// Byte length of a compressed-section container header:
+/// `magic (4) + codec (1) + uncompressed_len (8)`.
+const COMPRESSED_HEADER_LEN: usize = 13;
+
+/// A section's location in the backing stream: a byte `offset` and a `size`,
+/// plus the `codec` it was stored with (`None` for a plain, uncompressed
+/// section).
+///
+/// Sections no longer carry a pointer into an in-memory buffer, so a `BinFile`
+/// can be backed by any `Read + Seek` stream and only the bytes actually read
+/// are pulled into memory.
 #[derive(Debug, Clone)]
 pub struct Section {
-    start: *const u8,
+    offset: u64,
     size: u64,
+    codec: Option<Codec>,
 }
 
-pub struct BinFile {
-    size: usize,
-    addr: Vec<u8>,
+pub struct BinFile<R: Read + Seek> {
+    reader: R,
     file_type: String,
-    pos: usize,
+    pos: u64,
     version: u32,
     sections: HashMap<u32, Vec<Section>>,
     reading_section: Option<Section>,
+    buf: Vec<u8>,
+}
+
+/// One part of a split file: the open `file`, its `start` offset in the logical
+/// stream, and its `len`.
+struct Segment {
+    file: File,
+    start: u64,
+    len: u64,
 }
 
-impl BinFile {
+/// A `Read + Seek` stream that presents a sequence of files as the single
+/// logical byte stream formed by their concatenation.
+///
+/// Reads and seeks address logical offsets; a logical offset is translated to
+/// the containing part plus an in-part offset, and a single read transparently
+/// crosses part boundaries.
+pub struct SplitReader {
+    segments: Vec<Segment>,
+    total_len: u64,
+    pos: u64,
+}
+
+impl SplitReader {
+    fn open(paths: &[PathBuf]) -> io::Result<SplitReader> {
+        let mut segments = Vec::with_capacity(paths.len());
+        let mut start = 0u64;
+        for path in paths {
+            let file = File::open(path)?;
+            let len = file.metadata()?.len();
+            segments.push(Segment { file, start, len });
+            start += len;
+        }
+
+        Ok(SplitReader { segments, total_len: start, pos: 0 })
+    }
+}
+
+impl Read for SplitReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+        while written < buf.len() && self.pos < self.total_len {
+            let pos = self.pos;
+            let seg = match self.segments.iter_mut().find(|s| pos >= s.start && pos < s.start + s.len) {
+                Some(seg) => seg,
+                None => break,
+            };
+
+            let in_seg = pos - seg.start;
+            seg.file.seek(SeekFrom::Start(in_seg))?;
+            let want = std::cmp::min((buf.len() - written) as u64, seg.len - in_seg) as usize;
+            let n = seg.file.read(&mut buf[written..written + want])?;
+            if n == 0 {
+                break;
+            }
+            written += n;
+            self.pos += n as u64;
+        }
+
+        Ok(written)
+    }
+}
+
+impl Seek for SplitReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => self.total_len as i64 + offset,
+            SeekFrom::Current(offset) => self.pos as i64 + offset,
+        };
+
+        if target < 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Seek before start of stream"));
+        }
+
+        self.pos = target as u64;
+        Ok(self.pos)
+    }
+}
+
+impl BinFile<SplitReader> {
+    /// Parses a file split across numbered parts (`.part0`, `.part1`, …) as a
+    /// single logical stream.
+    ///
+    /// The parts are presented in the order given, so section offsets computed
+    /// in [`from_reader`](BinFile::from_reader) index into the concatenation and
+    /// a split file parses identically to the joined one.
+    pub fn from_split_parts(paths: &[PathBuf], expected_type: &str, max_version: u32) -> Result<Self, Box<dyn Error>> {
+        let reader = SplitReader::open(paths)?;
+        Self::from_reader(reader, expected_type, max_version)
+    }
+}
+
+impl BinFile<Cursor<Vec<u8>>> {
+    /// Parses a file already held in memory.
+    ///
+    /// This is a thin convenience over [`BinFile::from_reader`] backed by an
+    /// in-memory [`Cursor`]; `file_size` is accepted for backwards
+    /// compatibility and no longer needs to match the slice length.
     pub fn new(file_data: &[u8], file_size: usize, expected_type: &str, max_version: u32) -> Result<Self, Box<dyn Error>> {
-        let mut addr = Vec::with_capacity(file_size);
-        addr.extend_from_slice(file_data);
+        let _ = file_size;
+        Self::from_reader(Cursor::new(file_data.to_vec()), expected_type, max_version)
+    }
+}
 
-        let file_type = String::from_utf8(addr[0..4].to_vec())?;
-        let mut pos = 4;
+impl<R: Read + Seek> BinFile<R> {
+    /// Parses the header and section table of a `Read + Seek` stream without
+    /// loading the section bodies into memory.
+    ///
+    /// Only the `(type, size, offset)` triples are recorded; section data is
+    /// pulled through the reader on demand, so memory use stays proportional to
+    /// the largest chunk read rather than the size of the file.
+    pub fn from_reader(mut reader: R, expected_type: &str, max_version: u32) -> Result<Self, Box<dyn Error>> {
+        let file_len = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(0))?;
+        let mut pos: u64 = 0;
+
+        let mut magic = [0u8; 4];
+        Self::read_exact_at(&mut reader, &mut magic, &mut pos, file_len)?;
+        let file_type = String::from_utf8(magic.to_vec())?;
 
         if file_type != expected_type {
             return Err(Box::new(InvalidFileType { expected: expected_type.to_string(), found: file_type }));
         }
 
-        let version = Self::read_u32_le(&addr, &mut pos);
+        let version = Self::read_u32_le(&mut reader, &mut pos, file_len)?;
         if version > max_version {
             return Err(Box::new(InvalidVersion { max_version, found_version: version }));
         }
 
-        let n_sections = Self::read_u32_le(&addr, &mut pos);
+        let n_sections = Self::read_u32_le(&mut reader, &mut pos, file_len)?;
         let mut sections = HashMap::new();
 
         for _ in 0..n_sections {
-            let s_type = Self::read_u32_le(&addr, &mut pos);
-            let s_size = Self::read_u64_le(&addr, &mut pos);
+            let s_type = Self::read_u32_le(&mut reader, &mut pos, file_len)?;
+            let s_size = Self::read_u64_le(&mut reader, &mut pos, file_len)?;
+
+            let offset = pos;
+            // Validate the section body fits before recording it, so a bogus
+            // size can't leave a `Section` pointing past the end of the stream.
+            // Use checked arithmetic so an `s_size` near `u64::MAX` rejects
+            // cleanly instead of overflowing the bounds check itself.
+            let end = match offset.checked_add(s_size) {
+                Some(end) if end <= file_len => end,
+                _ => return Err(Box::new(UnexpectedEof { needed: s_size as usize, remaining: (file_len - offset) as usize })),
+            };
+
+            // Peek the container header so a compressed section is flagged in
+            // the section table and decompressed transparently on read.
+            let mut codec = None;
+            if s_size >= COMPRESSED_HEADER_LEN as u64 {
+                let mut head = [0u8; 5];
+                reader.seek(SeekFrom::Start(offset))?;
+                reader.read_exact(&mut head)?;
+                if head[0..4] == COMPRESSED_MAGIC {
+                    codec = Some(Codec::from_u8(head[4])?);
+                }
+            }
 
-            sections.entry(s_type).or_insert_with(Vec::new).push(Section { start: addr[pos..].as_ptr(), size: s_size });
-            pos += s_size as usize;
+            reader.seek(SeekFrom::Start(end))?;
+            pos = end;
+
+            sections.entry(s_type).or_insert_with(Vec::new).push(Section { offset, size: s_size, codec });
         }
 
         Ok(BinFile {
-            size: file_size,
-            addr,
+            reader,
             file_type,
             pos: 0,
             version,
             sections,
             reading_section: None,
+            buf: Vec::new(),
         })
     }
 
+    fn read_exact_at(reader: &mut R, buf: &mut [u8], pos: &mut u64, file_len: u64) -> Result<(), Box<dyn Error>> {
+        let remaining = file_len - *pos;
+        if (buf.len() as u64) > remaining {
+            return Err(Box::new(UnexpectedEof { needed: buf.len(), remaining: remaining as usize }));
+        }
+        reader.read_exact(buf)?;
+        *pos += buf.len() as u64;
+        Ok(())
+    }
+
+    fn read_u32_le(reader: &mut R, pos: &mut u64, file_len: u64) -> Result<u32, Box<dyn Error>> {
+        let mut buf = [0u8; 4];
+        Self::read_exact_at(reader, &mut buf, pos, file_len)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn read_u64_le(reader: &mut R, pos: &mut u64, file_len: u64) -> Result<u64, Box<dyn Error>> {
+        let mut buf = [0u8; 8];
+        Self::read_exact_at(reader, &mut buf, pos, file_len)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    /// The 4-byte file type read from the header.
+    pub fn file_type(&self) -> &str {
+        &self.file_type
+    }
+
+    /// The file format version read from the header.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
     pub fn start_read_section(&mut self, section_id: u32, section_pos: u32) -> Result<(), Box<dyn Error>> {
         if !self.sections.contains_key(&section_id) {
             return Err(Box::new(RangeError::new(&format!("Section does not exist: {}", section_id))));
@@ -121,7 +449,11 @@ impl BinFile {
         }
 
         let section = self.sections[&section_id][section_pos as usize].clone();
-        self.pos = (section.start as usize) - (self.addr.as_ptr() as usize);
+        if section.codec.is_some() {
+            return Err(Box::new(RangeError::new("Section is compressed; read it with get_section_slice")));
+        }
+        self.reader.seek(SeekFrom::Start(section.offset))?;
+        self.pos = section.offset;
         self.reading_section = Some(section);
 
         Ok(())
@@ -129,7 +461,8 @@ impl BinFile {
 
     pub fn end_read_section(&mut self, check: bool) -> Result<(), Box<dyn Error>> {
         if check {
-            if (self.addr.as_ptr() as usize + self.pos) - (self.reading_section.as_ref().unwrap().start as usize) != self.reading_section.as_ref().unwrap().size as usize {
+            let section = self.reading_section.as_ref().unwrap();
+            if self.pos - section.offset != section.size {
                 return Err(Box::new(RangeError::new("Invalid section size")));
             }
         }
@@ -138,7 +471,9 @@ impl BinFile {
         Ok(())
     }
 
-    pub fn get_section_data(&self, section_id: u32, section_pos: u32) -> Result<*const u8, Box<dyn Error>> {
+    /// Reads a section's on-disk bytes through the backing stream into the
+    /// internal buffer, without interpreting any compression container.
+    fn read_section_raw(&mut self, section_id: u32, section_pos: u32) -> Result<&[u8], Box<dyn Error>> {
         if !self.sections.contains_key(&section_id) {
             return Err(Box::new(RangeError::new(&format!("Section does not exist: {}", section_id))));
         }
@@ -147,7 +482,105 @@ impl BinFile {
             return Err(Box::new(RangeError::new(&format!("Section pos too big. There are {} and it's trying to access section: {}", self.sections[&section_id].len(), section_pos))));
         }
 
-        Ok(self.sections[&section_id][section_pos as usize].start)
+        let section = self.sections[&section_id][section_pos as usize].clone();
+        self.reader.seek(SeekFrom::Start(section.offset))?;
+        self.buf.resize(section.size as usize, 0);
+        self.reader.read_exact(&mut self.buf)?;
+
+        Ok(&self.buf)
+    }
+
+    /// Returns the logical bytes of a section, reading them through the backing
+    /// stream into an owned buffer and serving the slice from there.
+    ///
+    /// A section stored compressed (flagged in the section table during parse)
+    /// is decompressed transparently, so callers get the original payload
+    /// regardless of how it was stored. The buffer is reused between calls, so
+    /// the slice is only valid until the next read.
+    pub fn get_section_slice(&mut self, section_id: u32, section_pos: u32) -> Result<&[u8], Box<dyn Error>> {
+        let codec = {
+            if !self.sections.contains_key(&section_id) {
+                return Err(Box::new(RangeError::new(&format!("Section does not exist: {}", section_id))));
+            }
+            if section_pos as usize >= self.sections[&section_id].len() {
+                return Err(Box::new(RangeError::new(&format!("Section pos too big. There are {} and it's trying to access section: {}", self.sections[&section_id].len(), section_pos))));
+            }
+            self.sections[&section_id][section_pos as usize].codec
+        };
+
+        self.read_section_raw(section_id, section_pos)?;
+
+        if let Some(codec) = codec {
+            if self.buf.len() < COMPRESSED_HEADER_LEN {
+                return Err(Box::new(RangeError::new("Compressed section too small for header")));
+            }
+            let uncompressed_len = u64::from_le_bytes(self.buf[5..COMPRESSED_HEADER_LEN].try_into().unwrap()) as usize;
+            self.buf = codec.decompress(&self.buf[COMPRESSED_HEADER_LEN..], uncompressed_len)?;
+        }
+
+        Ok(&self.buf)
+    }
+
+    /// Reads a section and returns its decompressed payload as an owned `Vec`.
+    ///
+    /// Equivalent to [`get_section_slice`](BinFile::get_section_slice) followed
+    /// by `to_vec`; retained for callers that want to own the result.
+    pub fn read_section_decompressed(&mut self, section_id: u32, section_pos: u32) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(self.get_section_slice(section_id, section_pos)?.to_vec())
+    }
+
+    /// Computes a digest over a section's on-disk bytes (the stored container,
+    /// for a compressed section), matching what the writer checksummed.
+    pub fn checksum_section(&mut self, section_id: u32, section_pos: u32, algo: ChecksumAlgo) -> Result<Checksum, Box<dyn Error>> {
+        let data = self.read_section_raw(section_id, section_pos)?;
+        Ok(algo.digest(data))
+    }
+
+    /// Verifies a section against an `expected` digest, returning an error if it
+    /// does not match.
+    pub fn verify_section(&mut self, section_id: u32, section_pos: u32, expected: &Checksum) -> Result<(), Box<dyn Error>> {
+        let got = self.checksum_section(section_id, section_pos, expected.algo())?;
+        if &got != expected {
+            return Err(Box::new(RangeError::new(&format!("Checksum mismatch for section {} (pos {})", section_id, section_pos))));
+        }
+        Ok(())
+    }
+
+    /// Walks the `(section_type, section_pos, algo, digest)` records held in a
+    /// checksum section (as written by
+    /// [`BinFileWriter::request_checksum_section`]) and verifies every referenced
+    /// section in one call.
+    pub fn verify_all(&mut self, checksum_section_id: u32, checksum_section_pos: u32) -> Result<(), Box<dyn Error>> {
+        let records = self.get_section_slice(checksum_section_id, checksum_section_pos)?.to_vec();
+
+        let mut pos = 0usize;
+        let mut take = |len: usize| -> Result<&[u8], Box<dyn Error>> {
+            if pos + len > records.len() {
+                return Err(Box::new(RangeError::new("Truncated checksum record")));
+            }
+            let slice = &records[pos..pos + len];
+            pos += len;
+            Ok(slice)
+        };
+
+        let n_records = u32::from_le_bytes(take(4)?.try_into().unwrap());
+        let mut expected = Vec::with_capacity(n_records as usize);
+        for _ in 0..n_records {
+            let section_type = u32::from_le_bytes(take(4)?.try_into().unwrap());
+            let section_pos = u32::from_le_bytes(take(4)?.try_into().unwrap());
+            let algo = ChecksumAlgo::from_u8(take(1)?[0])?;
+            let checksum = match algo {
+                ChecksumAlgo::Crc32 => Checksum::Crc32(u32::from_le_bytes(take(4)?.try_into().unwrap())),
+                ChecksumAlgo::Sha256 => Checksum::Sha256(take(32)?.try_into().unwrap()),
+            };
+            expected.push((section_type, section_pos, checksum));
+        }
+
+        for (section_type, section_pos, checksum) in &expected {
+            self.verify_section(*section_type, *section_pos, checksum)?;
+        }
+
+        Ok(())
     }
 
     pub fn get_section_size(&self, section_id: u32, section_pos: u32) -> Result<u64, Box<dyn Error>> {
@@ -162,21 +595,157 @@ impl BinFile {
         Ok(self.sections[&section_id][section_pos as usize].size)
     }
 
-    fn read_u32_le(data: &[u8], pos: &mut usize) -> u32 {
-        let result = u32::from_le_bytes(data[*pos..*pos + 4].try_into().unwrap());
-        *pos += 4;
-        result
+    /// Reads the next `len` bytes of the current section through the backing
+    /// stream, returning them as a slice borrowed from an internal buffer.
+    ///
+    /// The read is bounds-checked against the remaining bytes of the section
+    /// opened with [`start_read_section`](BinFile::start_read_section), so it
+    /// can never run past the section boundary into the next section's data.
+    ///
+    /// The backing stream is re-seeked to the section cursor first, so a
+    /// `get_section_slice`/`checksum_section` call (which move the reader
+    /// elsewhere) interleaved with reads cannot make this read from the wrong
+    /// offset. The buffer is reused between calls, so the slice is only valid
+    /// until the next read.
+    pub fn read_slice(&mut self, len: usize) -> Result<&[u8], Box<dyn Error>> {
+        let section = self.reading_section.as_ref().ok_or_else(|| RangeError::new("Not reading a section"))?;
+        let remaining = (section.offset + section.size) - self.pos;
+        if len as u64 > remaining {
+            return Err(Box::new(RangeError::new("Read past end of section")));
+        }
+
+        self.reader.seek(SeekFrom::Start(self.pos))?;
+        self.buf.resize(len, 0);
+        self.reader.read_exact(&mut self.buf)?;
+        self.pos += len as u64;
+        Ok(&self.buf)
+    }
+}
+
+/// Builds a binary file in the same layout [`BinFile`] parses.
+///
+/// Because a section's size isn't known until its data has been written, each
+/// section is buffered in full and the `(type, size, data)` triples are emitted
+/// by [`finish`](BinFileWriter::finish). Round-tripping a file through
+/// [`BinFile`] and back through a `BinFileWriter` is byte-identical.
+pub struct BinFileWriter {
+    file_type: String,
+    version: u32,
+    sections: Vec<(u32, Vec<u8>)>,
+    current: Option<(u32, Option<Codec>, Vec<u8>)>,
+    checksum_request: Option<(u32, ChecksumAlgo)>,
+}
+
+impl BinFileWriter {
+    pub fn new(file_type: &str, version: u32) -> BinFileWriter {
+        BinFileWriter {
+            file_type: file_type.to_string(),
+            version,
+            sections: Vec::new(),
+            current: None,
+            checksum_request: None,
+        }
+    }
+
+    /// Requests a trailing section of type `section_type` holding a digest
+    /// record for every section written before
+    /// [`finish`](BinFileWriter::finish), computed with `algo` when `finish`
+    /// runs.
+    pub fn request_checksum_section(&mut self, section_type: u32, algo: ChecksumAlgo) {
+        self.checksum_request = Some((section_type, algo));
+    }
+
+    /// Begins a plain section whose bytes are written verbatim, with no
+    /// compression container header.
+    pub fn start_write_section(&mut self, section_type: u32) -> Result<(), Box<dyn Error>> {
+        if self.current.is_some() {
+            return Err(Box::new(RangeError::new("Already writing a section")));
+        }
+        self.current = Some((section_type, None, Vec::new()));
+        Ok(())
+    }
+
+    /// Begins a section stored as a `{ magic, codec, uncompressed_len }`
+    /// container that [`BinFile::get_section_slice`] (and
+    /// [`BinFile::read_section_decompressed`]) transparently unwraps.
+    ///
+    /// The container header is written for every codec, including `Codec::None`
+    /// (which stores the bytes uncompressed but still with the header), so such
+    /// a section cannot be read with [`BinFile::start_read_section`].
+    pub fn start_write_section_compressed(&mut self, section_type: u32, codec: Codec) -> Result<(), Box<dyn Error>> {
+        if self.current.is_some() {
+            return Err(Box::new(RangeError::new("Already writing a section")));
+        }
+        self.current = Some((section_type, Some(codec), Vec::new()));
+        Ok(())
     }
 
-    fn read_u64_le(data: &[u8], pos: &mut usize) -> u64 {
-        let result = u64::from_le_bytes(data[*pos..*pos + 8].try_into().unwrap());
-        *pos += 8;
-        result
+    pub fn write(&mut self, data: &[u8]) -> Result<(), Box<dyn Error>> {
+        match self.current.as_mut() {
+            Some((_, _, buf)) => {
+                buf.extend_from_slice(data);
+                Ok(())
+            }
+            None => Err(Box::new(RangeError::new("Not writing a section"))),
+        }
+    }
+
+    pub fn end_write_section(&mut self) -> Result<(), Box<dyn Error>> {
+        match self.current.take() {
+            Some((section_type, None, raw)) => {
+                self.sections.push((section_type, raw));
+                Ok(())
+            }
+            Some((section_type, Some(codec), raw)) => {
+                let blob = codec.compress(&raw)?;
+                let mut container = Vec::with_capacity(COMPRESSED_HEADER_LEN + blob.len());
+                container.extend_from_slice(&COMPRESSED_MAGIC);
+                container.push(codec.to_u8());
+                container.extend_from_slice(&(raw.len() as u64).to_le_bytes());
+                container.extend_from_slice(&blob);
+                self.sections.push((section_type, container));
+                Ok(())
+            }
+            None => Err(Box::new(RangeError::new("Not writing a section"))),
+        }
     }
 
-    pub fn read(&mut self, len: u64) -> Result<*const u8, Box<dyn Error>> {
-        let start = self.addr[self.pos..].as_ptr();
-        self.pos += len as usize;
-        Ok(start)
+    pub fn finish<W: Write>(mut self, mut out: W) -> Result<(), Box<dyn Error>> {
+        if self.current.is_some() {
+            return Err(Box::new(RangeError::new("Section still open")));
+        }
+
+        if let Some((section_type, algo)) = self.checksum_request.take() {
+            let mut records = Vec::new();
+            records.extend_from_slice(&(self.sections.len() as u32).to_le_bytes());
+
+            let mut counts: HashMap<u32, u32> = HashMap::new();
+            for (s_type, data) in &self.sections {
+                let s_pos = counts.entry(*s_type).or_insert(0);
+                records.extend_from_slice(&s_type.to_le_bytes());
+                records.extend_from_slice(&s_pos.to_le_bytes());
+                records.push(algo.to_u8());
+                algo.digest(data).write_to(&mut records);
+                *s_pos += 1;
+            }
+
+            self.sections.push((section_type, records));
+        }
+
+        if self.file_type.len() != 4 {
+            return Err(Box::new(RangeError::new("File type must be exactly 4 bytes")));
+        }
+
+        out.write_all(self.file_type.as_bytes())?;
+        out.write_all(&self.version.to_le_bytes())?;
+        out.write_all(&(self.sections.len() as u32).to_le_bytes())?;
+
+        for (section_type, data) in &self.sections {
+            out.write_all(&section_type.to_le_bytes())?;
+            out.write_all(&(data.len() as u64).to_le_bytes())?;
+            out.write_all(data)?;
+        }
+
+        Ok(())
     }
 }
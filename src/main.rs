@@ -1,7 +1,10 @@
 pub mod binfile_utils;
 
+#[cfg(test)]
+mod binfile_tests;
+
 use std::fs::File;
-use std::io::{self, Read};
+use std::io::Read;
 use binfile_utils::BinFile;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -11,17 +14,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut file_data = Vec::new();
     file.read_to_end(&mut file_data)?;
 
-    let bin_file = BinFile::new(&file_data, file_data.len(), "zkey", 1)?;
+    let mut bin_file = BinFile::new(&file_data, file_data.len(), "zkey", 1)?;
 
-    let section_data = bin_file.get_section_data(2, 0)?;
     let section_size = bin_file.get_section_size(2, 0)?;
-
     println!("Section size: {}", section_size);
+
+    let section_data = bin_file.get_section_slice(2, 0)?;
     println!("section data: {:?}", section_data);
-    unsafe {
-        for i in 0..section_size {
-            println!("{}", *section_data.add(i as usize));
-        }
+    for byte in section_data {
+        println!("{}", byte);
     }
 
     Ok(())